@@ -0,0 +1,52 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct Program {
+    pub statements: Vec<Statement>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Statement {
+    Let(LetStatement),
+    Return(ReturnStatement),
+    Expression(ExpressionStatement),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LetStatement {
+    pub name: Identifier,
+    pub value: Expression,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReturnStatement {
+    pub value: Expression,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExpressionStatement {
+    pub expression: Expression,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expression {
+    Identifier(Identifier),
+    IntegerLiteral(i64),
+    Boolean(bool),
+    Prefix(PrefixExpression),
+    Infix(InfixExpression),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Identifier(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrefixExpression {
+    pub operator: String,
+    pub right: Box<Expression>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InfixExpression {
+    pub left: Box<Expression>,
+    pub operator: String,
+    pub right: Box<Expression>,
+}
@@ -0,0 +1,318 @@
+use crate::token::Token;
+
+pub struct Lexer {
+    input: Vec<u8>,
+    position: usize,
+    read_position: usize,
+    ch: u8,
+    eof_sent: bool,
+}
+
+impl Lexer {
+    pub fn new(input: &str) -> Self {
+        let mut lexer = Lexer {
+            input: input.as_bytes().to_vec(),
+            position: 0,
+            read_position: 0,
+            ch: 0,
+            eof_sent: false,
+        };
+        lexer.read_char();
+        lexer
+    }
+
+    fn read_char(&mut self) {
+        self.ch = if self.read_position >= self.input.len() {
+            0
+        } else {
+            self.input[self.read_position]
+        };
+        self.position = self.read_position;
+        self.read_position += 1;
+    }
+
+    fn peek_char(&self) -> u8 {
+        if self.read_position >= self.input.len() {
+            0
+        } else {
+            self.input[self.read_position]
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.ch, b' ' | b'\t' | b'\n' | b'\r') {
+            self.read_char();
+        }
+    }
+
+    fn read_identifier(&mut self) -> String {
+        let start = self.position;
+        while is_letter(self.ch) {
+            self.read_char();
+        }
+        String::from_utf8_lossy(&self.input[start..self.position]).into_owned()
+    }
+
+    fn read_number(&mut self) -> String {
+        let start = self.position;
+        while self.ch.is_ascii_digit() {
+            self.read_char();
+        }
+        String::from_utf8_lossy(&self.input[start..self.position]).into_owned()
+    }
+
+    pub fn next_token(&mut self) -> Token {
+        self.next_parsed_token().token
+    }
+
+    /// Like [`next_token`](Self::next_token), but also reports where the
+    /// token came from and, for a malformed token, why it failed.
+    pub fn next_parsed_token(&mut self) -> ParsedToken {
+        self.skip_whitespace();
+        let start = self.position;
+
+        if is_letter(self.ch) {
+            let token = Token::lookup_ident(&self.read_identifier());
+            return ParsedToken::ok(token, start, self.position - start);
+        }
+        if self.ch.is_ascii_digit() {
+            let digits = self.read_number();
+            let len = self.position - start;
+            return match digits.parse() {
+                Ok(value) => ParsedToken::ok(Token::INT(value), start, len),
+                Err(_) => ParsedToken::err(
+                    Token::ILLEGAL,
+                    start,
+                    len,
+                    LexError {
+                        kind: LexErrorKind::IntegerOverflow(digits),
+                        position: start,
+                        len,
+                    },
+                ),
+            };
+        }
+
+        let (token, kind) = match self.ch {
+            b'=' => {
+                if self.peek_char() == b'=' {
+                    self.read_char();
+                    (Token::EQ, None)
+                } else {
+                    (Token::ASSIGN, None)
+                }
+            }
+            b'!' => {
+                if self.peek_char() == b'=' {
+                    self.read_char();
+                    (Token::NOTEQ, None)
+                } else {
+                    (Token::BANG, None)
+                }
+            }
+            b'+' => (Token::PLUS, None),
+            b'-' => (Token::MINUS, None),
+            b'*' => (Token::ASTERISK, None),
+            b'/' => (Token::SLASH, None),
+            b'<' => (Token::LT, None),
+            b'>' => (Token::GT, None),
+            b';' => (Token::SEMICOLON, None),
+            b',' => (Token::COMMA, None),
+            b'(' => (Token::LPAREN, None),
+            b')' => (Token::RPAREN, None),
+            b'{' => (Token::LBRACE, None),
+            b'}' => (Token::RBRACE, None),
+            0 => (Token::EOF, None),
+            ch => (Token::ILLEGAL, Some(LexErrorKind::IllegalCharacter(ch))),
+        };
+
+        self.read_char();
+        let len = self.position - start;
+        match kind {
+            Some(kind) => ParsedToken::err(
+                token,
+                start,
+                len,
+                LexError {
+                    kind,
+                    position: start,
+                    len,
+                },
+            ),
+            None => ParsedToken::ok(token, start, len),
+        }
+    }
+}
+
+/// Why a [`ParsedToken`] could not be scanned cleanly.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LexErrorKind {
+    /// A byte that doesn't start any valid Monkey token.
+    IllegalCharacter(u8),
+    /// A digit run that doesn't fit in an `i64`, with the offending text.
+    IntegerOverflow(String),
+}
+
+/// A structured lexing failure, with the span of the offending token.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LexError {
+    pub kind: LexErrorKind,
+    pub position: usize,
+    pub len: usize,
+}
+
+/// A [`Token`] annotated with its source span, for error reporting.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedToken {
+    pub token: Token,
+    pub position: usize,
+    pub len: usize,
+    pub error: Option<LexError>,
+}
+
+impl ParsedToken {
+    fn ok(token: Token, position: usize, len: usize) -> Self {
+        ParsedToken {
+            token,
+            position,
+            len,
+            error: None,
+        }
+    }
+
+    fn err(token: Token, position: usize, len: usize, error: LexError) -> Self {
+        ParsedToken {
+            token,
+            position,
+            len,
+            error: Some(error),
+        }
+    }
+}
+
+/// Lexes `input` in full, returning every token alongside any lexing errors
+/// encountered, so callers can report all problems at once.
+pub fn tokenize(input: &str) -> (Vec<ParsedToken>, Vec<LexError>) {
+    let mut lexer = Lexer::new(input);
+    let mut tokens = Vec::new();
+    let mut errors = Vec::new();
+
+    loop {
+        let parsed = lexer.next_parsed_token();
+        if let Some(error) = parsed.error.clone() {
+            errors.push(error);
+        }
+        let is_eof = parsed.token == Token::EOF;
+        tokens.push(parsed);
+        if is_eof {
+            break;
+        }
+    }
+
+    (tokens, errors)
+}
+
+fn is_letter(ch: u8) -> bool {
+    ch.is_ascii_alphabetic() || ch == b'_'
+}
+
+impl Iterator for Lexer {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        if self.eof_sent {
+            return None;
+        }
+        let token = self.next_token();
+        if token == Token::EOF {
+            self.eof_sent = true;
+        }
+        Some(token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_parsed_token_reports_position_and_length() {
+        let mut lexer = Lexer::new("  foobar");
+        let parsed = lexer.next_parsed_token();
+
+        assert_eq!(parsed.token, Token::IDENT("foobar".to_string()));
+        assert_eq!(parsed.position, 2);
+        assert_eq!(parsed.len, 6);
+        assert_eq!(parsed.error, None);
+    }
+
+    #[test]
+    fn next_parsed_token_reports_illegal_character() {
+        let mut lexer = Lexer::new("@");
+        let parsed = lexer.next_parsed_token();
+
+        assert_eq!(parsed.token, Token::ILLEGAL);
+        assert_eq!(
+            parsed.error,
+            Some(LexError {
+                kind: LexErrorKind::IllegalCharacter(b'@'),
+                position: 0,
+                len: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn next_parsed_token_reports_integer_overflow() {
+        let digits = "99999999999999999999999";
+        let mut lexer = Lexer::new(digits);
+        let parsed = lexer.next_parsed_token();
+
+        assert_eq!(parsed.token, Token::ILLEGAL);
+        assert_eq!(
+            parsed.error,
+            Some(LexError {
+                kind: LexErrorKind::IntegerOverflow(digits.to_string()),
+                position: 0,
+                len: digits.len(),
+            })
+        );
+    }
+
+    #[test]
+    fn tokenize_collects_all_tokens_and_errors() {
+        let (tokens, errors) = tokenize("let x = 5; @");
+
+        let token_kinds: Vec<Token> = tokens.into_iter().map(|parsed| parsed.token).collect();
+        assert_eq!(
+            token_kinds,
+            vec![
+                Token::LET,
+                Token::IDENT("x".to_string()),
+                Token::ASSIGN,
+                Token::INT(5),
+                Token::SEMICOLON,
+                Token::ILLEGAL,
+                Token::EOF,
+            ]
+        );
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, LexErrorKind::IllegalCharacter(b'@'));
+    }
+
+    #[test]
+    fn lexer_as_iterator_yields_eof_exactly_once() {
+        let mut lexer = Lexer::new("+");
+
+        assert_eq!(lexer.next(), Some(Token::PLUS));
+        assert_eq!(lexer.next(), Some(Token::EOF));
+        assert_eq!(lexer.next(), None);
+        assert_eq!(lexer.next(), None);
+    }
+
+    #[test]
+    fn lexer_composes_with_iterator_adapters() {
+        let tokens: Vec<Token> = Lexer::new("+ - *").take_while(|t| *t != Token::EOF).collect();
+        assert_eq!(tokens, vec![Token::PLUS, Token::MINUS, Token::ASTERISK]);
+    }
+}
@@ -1,18 +1,42 @@
-use monkey::token::Token;
+use std::io::{self, BufRead, Write};
+
+use monkey::lexer::Lexer;
+use monkey::parser::Parser;
+
+const PROMPT: &str = ">> ";
 
 fn main() {
-    let input = String::from("=+(){},;");
-    let expected_tokens: Vec<Token> = vec![
-        Token::ASSIGN,
-        Token::PLUS,
-        Token::LPAREN,
-        Token::RPAREN,
-        Token::LBRACE,
-        Token::RBRACE,
-        Token::COMMA,
-        Token::SEMICOLON,
-    ];
-    for v in input.split("").collect::<Vec<&str>>() {
-        println!("{}", v)
+    let stdin = io::stdin();
+    let mut stdout = io::stdout();
+
+    loop {
+        print!("{}", PROMPT);
+        stdout.flush().expect("failed to flush stdout");
+
+        let mut line = String::new();
+        let bytes_read = stdin
+            .lock()
+            .read_line(&mut line)
+            .expect("failed to read line");
+        if bytes_read == 0 {
+            break;
+        }
+
+        let lexer = Lexer::new(&line);
+        let mut parser = Parser::new(lexer);
+        let program = parser.parse_program();
+
+        for error in &parser.lex_errors {
+            println!("\t{:?}", error);
+        }
+
+        if !parser.errors.is_empty() {
+            for error in &parser.errors {
+                println!("\t{}", error.0);
+            }
+            continue;
+        }
+
+        println!("{:#?}", program);
     }
 }
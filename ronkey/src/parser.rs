@@ -0,0 +1,311 @@
+use crate::ast::{
+    Expression, ExpressionStatement, Identifier, InfixExpression, LetStatement, PrefixExpression,
+    Program, ReturnStatement, Statement,
+};
+use crate::lexer::{LexError, Lexer};
+use crate::token::Token;
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+enum Precedence {
+    Lowest,
+    Equals,      // == or !=
+    LessGreater, // < or >
+    Sum,         // + or -
+    Product,     // * or /
+    Prefix,      // -x or !x
+}
+
+fn precedence_of(token: &Token) -> Precedence {
+    match token {
+        Token::EQ | Token::NOTEQ => Precedence::Equals,
+        Token::LT | Token::GT => Precedence::LessGreater,
+        Token::PLUS | Token::MINUS => Precedence::Sum,
+        Token::SLASH | Token::ASTERISK => Precedence::Product,
+        _ => Precedence::Lowest,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError(pub String);
+
+pub struct Parser {
+    lexer: Lexer,
+    cur_token: Token,
+    peek_token: Token,
+    pub errors: Vec<ParseError>,
+    pub lex_errors: Vec<LexError>,
+}
+
+impl Parser {
+    pub fn new(mut lexer: Lexer) -> Self {
+        let mut lex_errors = Vec::new();
+        let cur_token = Self::scan(&mut lexer, &mut lex_errors);
+        let peek_token = Self::scan(&mut lexer, &mut lex_errors);
+        Parser {
+            lexer,
+            cur_token,
+            peek_token,
+            errors: Vec::new(),
+            lex_errors,
+        }
+    }
+
+    /// Pulls the next token from the lexer, recording any `LexError` it
+    /// reports in `lex_errors` so the position and kind of a malformed token
+    /// are kept; the token handed to the parser is the bare `ILLEGAL`.
+    fn scan(lexer: &mut Lexer, lex_errors: &mut Vec<LexError>) -> Token {
+        let parsed = lexer.next_parsed_token();
+        if let Some(error) = parsed.error {
+            lex_errors.push(error);
+        }
+        parsed.token
+    }
+
+    fn advance(&mut self) {
+        let next = Self::scan(&mut self.lexer, &mut self.lex_errors);
+        self.cur_token = std::mem::replace(&mut self.peek_token, next);
+    }
+
+    pub fn parse_program(&mut self) -> Program {
+        let mut statements = Vec::new();
+        while self.cur_token != Token::EOF {
+            if let Some(statement) = self.parse_statement() {
+                statements.push(statement);
+            }
+            self.advance();
+        }
+        Program { statements }
+    }
+
+    fn parse_statement(&mut self) -> Option<Statement> {
+        match self.cur_token {
+            Token::LET => self.parse_let_statement().map(Statement::Let),
+            Token::RETURN => self.parse_return_statement().map(Statement::Return),
+            _ => self.parse_expression_statement().map(Statement::Expression),
+        }
+    }
+
+    fn parse_let_statement(&mut self) -> Option<LetStatement> {
+        let name = match &self.peek_token {
+            Token::IDENT(name) => name.clone(),
+            other => {
+                self.errors.push(ParseError(format!(
+                    "expected next token to be IDENT, got {:?} instead",
+                    other
+                )));
+                return None;
+            }
+        };
+        self.advance();
+
+        if !self.expect_peek(&Token::ASSIGN) {
+            return None;
+        }
+        self.advance();
+
+        let value = self.parse_expression(Precedence::Lowest)?;
+        if self.peek_token == Token::SEMICOLON {
+            self.advance();
+        }
+
+        Some(LetStatement {
+            name: Identifier(name),
+            value,
+        })
+    }
+
+    fn parse_return_statement(&mut self) -> Option<ReturnStatement> {
+        self.advance();
+
+        let value = self.parse_expression(Precedence::Lowest)?;
+        if self.peek_token == Token::SEMICOLON {
+            self.advance();
+        }
+
+        Some(ReturnStatement { value })
+    }
+
+    fn parse_expression_statement(&mut self) -> Option<ExpressionStatement> {
+        let expression = self.parse_expression(Precedence::Lowest)?;
+        if self.peek_token == Token::SEMICOLON {
+            self.advance();
+        }
+        Some(ExpressionStatement { expression })
+    }
+
+    fn parse_expression(&mut self, precedence: Precedence) -> Option<Expression> {
+        let mut left = self.parse_prefix()?;
+
+        while self.peek_token != Token::SEMICOLON && precedence < precedence_of(&self.peek_token) {
+            self.advance();
+            left = self.parse_infix(left)?;
+        }
+
+        Some(left)
+    }
+
+    fn parse_prefix(&mut self) -> Option<Expression> {
+        match self.cur_token.clone() {
+            Token::IDENT(name) => Some(Expression::Identifier(Identifier(name))),
+            Token::INT(value) => Some(Expression::IntegerLiteral(value)),
+            Token::TRUE => Some(Expression::Boolean(true)),
+            Token::FALSE => Some(Expression::Boolean(false)),
+            Token::BANG | Token::MINUS => {
+                let operator = operator_literal(&self.cur_token);
+                self.advance();
+                let right = self.parse_expression(Precedence::Prefix)?;
+                Some(Expression::Prefix(PrefixExpression {
+                    operator,
+                    right: Box::new(right),
+                }))
+            }
+            Token::LPAREN => {
+                self.advance();
+                let expression = self.parse_expression(Precedence::Lowest)?;
+                if !self.expect_peek(&Token::RPAREN) {
+                    return None;
+                }
+                Some(expression)
+            }
+            other => {
+                self.errors.push(ParseError(format!(
+                    "no prefix parse function for {:?} found",
+                    other
+                )));
+                None
+            }
+        }
+    }
+
+    fn parse_infix(&mut self, left: Expression) -> Option<Expression> {
+        let operator = operator_literal(&self.cur_token);
+        let precedence = precedence_of(&self.cur_token);
+        self.advance();
+        let right = self.parse_expression(precedence)?;
+        Some(Expression::Infix(InfixExpression {
+            left: Box::new(left),
+            operator,
+            right: Box::new(right),
+        }))
+    }
+
+    /// Advances past `peek_token` if it matches `expected`, else records a
+    /// parse error and leaves the cursor where it was.
+    fn expect_peek(&mut self, expected: &Token) -> bool {
+        if std::mem::discriminant(&self.peek_token) == std::mem::discriminant(expected) {
+            self.advance();
+            true
+        } else {
+            self.errors.push(ParseError(format!(
+                "expected next token to be {:?}, got {:?} instead",
+                expected, self.peek_token
+            )));
+            false
+        }
+    }
+}
+
+fn operator_literal(token: &Token) -> String {
+    match token {
+        Token::PLUS => "+".to_string(),
+        Token::MINUS => "-".to_string(),
+        Token::BANG => "!".to_string(),
+        Token::ASTERISK => "*".to_string(),
+        Token::SLASH => "/".to_string(),
+        Token::LT => "<".to_string(),
+        Token::GT => ">".to_string(),
+        Token::EQ => "==".to_string(),
+        Token::NOTEQ => "!=".to_string(),
+        other => format!("{:?}", other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> Program {
+        let mut parser = Parser::new(Lexer::new(input));
+        let program = parser.parse_program();
+        assert!(
+            parser.errors.is_empty(),
+            "unexpected parser errors: {:?}",
+            parser.errors
+        );
+        program
+    }
+
+    /// Renders an `Expression` back to Monkey-ish source with explicit
+    /// parens around every operator, so precedence/associativity tests can
+    /// assert on a single string instead of matching nested AST shapes.
+    fn display(expr: &Expression) -> String {
+        match expr {
+            Expression::Identifier(ident) => ident.0.clone(),
+            Expression::IntegerLiteral(value) => value.to_string(),
+            Expression::Boolean(value) => value.to_string(),
+            Expression::Prefix(p) => format!("({}{})", p.operator, display(&p.right)),
+            Expression::Infix(i) => {
+                format!("({} {} {})", display(&i.left), i.operator, display(&i.right))
+            }
+        }
+    }
+
+    #[test]
+    fn parses_let_statements() {
+        let program = parse("let x = 5;\nlet y = true;\nlet foobar = y;");
+        assert_eq!(program.statements.len(), 3);
+
+        let names: Vec<&str> = program
+            .statements
+            .iter()
+            .map(|stmt| match stmt {
+                Statement::Let(let_stmt) => let_stmt.name.0.as_str(),
+                other => panic!("expected a let statement, got {:?}", other),
+            })
+            .collect();
+        assert_eq!(names, vec!["x", "y", "foobar"]);
+    }
+
+    #[test]
+    fn parses_return_statements() {
+        let program = parse("return 5;\nreturn true;\nreturn foobar;");
+        assert_eq!(program.statements.len(), 3);
+        assert!(program
+            .statements
+            .iter()
+            .all(|stmt| matches!(stmt, Statement::Return(_))));
+    }
+
+    #[test]
+    fn respects_operator_precedence() {
+        let cases = [
+            ("-a * b", "((-a) * b)"),
+            ("a + b * c", "(a + (b * c))"),
+            ("1 + 2 + 3", "((1 + 2) + 3)"),
+            ("(1 + 2) * 3", "((1 + 2) * 3)"),
+            ("!true == false", "((!true) == false)"),
+        ];
+
+        for (input, expected) in cases {
+            let program = parse(input);
+            assert_eq!(program.statements.len(), 1);
+            let Statement::Expression(stmt) = &program.statements[0] else {
+                panic!("expected an expression statement for {:?}", input);
+            };
+            assert_eq!(display(&stmt.expression), expected, "input: {}", input);
+        }
+    }
+
+    #[test]
+    fn collects_parse_error_on_missing_assign() {
+        let mut parser = Parser::new(Lexer::new("let x 5;"));
+        parser.parse_program();
+
+        assert_eq!(
+            parser.errors,
+            vec![ParseError(
+                "expected next token to be ASSIGN, got INT(5) instead".to_string()
+            )]
+        );
+    }
+}
@@ -1,35 +1,57 @@
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Token {
     ILLEGAL,
     EOF,
-    IDENT,
-    INT,
+
+    // Identifiers + literals
+    IDENT(String),
+    INT(i64),
+
+    // Operators
     ASSIGN,
     PLUS,
+    MINUS,
+    BANG,
+    ASTERISK,
+    SLASH,
+
+    LT,
+    GT,
+
+    EQ,
+    NOTEQ,
+
+    // Delimiters
     COMMA,
     SEMICOLON,
+
     LPAREN,
     RPAREN,
     LBRACE,
     RBRACE,
+
+    // Keywords
     FUNCTION,
     LET,
+    TRUE,
+    FALSE,
+    IF,
+    ELSE,
+    RETURN,
 }
 
 impl Token {
-    pub fn tokenize(str: &str) -> Self {
-        match str {
-            "=" => Self::ASSIGN,
-            "+" => Self::PLUS,
-            "func" => Self::FUNCTION,
+    /// Resolves a scanned word to its keyword `Token`, falling back to `IDENT`.
+    pub fn lookup_ident(ident: &str) -> Self {
+        match ident {
+            "fn" => Self::FUNCTION,
             "let" => Self::LET,
-            "(" => Self::LPAREN,
-            ")" => Self::RPAREN,
-            "{" => Self::LBRACE,
-            "}" => Self::RBRACE,
-            ";" => Self::SEMICOLON,
-            "," => Self::COMMA,
-            _ => Self::ILLEGAL,
+            "true" => Self::TRUE,
+            "false" => Self::FALSE,
+            "if" => Self::IF,
+            "else" => Self::ELSE,
+            "return" => Self::RETURN,
+            _ => Self::IDENT(ident.to_string()),
         }
     }
 }